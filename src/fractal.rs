@@ -19,14 +19,116 @@
 //! (./struct.PinkNoise.html).
 
 use std::default::Default;
-use cgmath::vector::Vector2;
+use cgmath::vector::{Vector2, Vector3};
 
-use primitives::{snoise_2d, NoiseModule};
+use primitives::{snoise_2d, snoise_3d, squirrel_hash, NoiseModule};
 use modifiers::Modifiable;
 
 static PINKNOISE_SCALE: f32 = 0.25;
 static BILLOWNOISE_SCALE: f32 = 0.25;
 
+/// Implemented by modules that can be seeded for reproducible output.
+pub trait Seedable: Sized {
+    /// Set the seed of this module, returning the updated module for
+    /// chaining.
+    fn set_seed(self, seed: uint) -> Self;
+
+    /// The current seed of this module.
+    fn seed(&self) -> uint;
+}
+
+/// Implemented by fractal noise modules, giving them a uniform, chainable
+/// builder API for their common octave/frequency/lacunarity parameters
+/// instead of requiring direct mutation of public fields.
+pub trait MultiFractal: Sized {
+    /// Set the number of octaves, returning the updated module for
+    /// chaining.
+    fn set_octaves(self, octaves: uint) -> Self;
+
+    /// Set the frequency, returning the updated module for chaining.
+    fn set_frequency(self, frequency: f32) -> Self;
+
+    /// Set the lacunarity, returning the updated module for chaining.
+    fn set_lacunarity(self, lacunarity: f32) -> Self;
+
+    /// Set the persistence, returning the updated module for chaining. The
+    /// default implementation is a no-op, since not every fractal module
+    /// has a persistence parameter: the Musgrave-style modules control
+    /// amplitude falloff with `h` (and, in `RidgedMultiNoise`, `gain`)
+    /// instead.
+    fn set_persistence(self, _persistence: f32) -> Self {
+        self
+    }
+}
+
+/// Checks that an octave count falls within the range supported by this
+/// module's `generate_2d`/`generate_3d` methods. Shared by every fractal
+/// module to avoid copy-pasting the same bounds check.
+fn check_octaves(octaves: uint) -> Result<(), &'static str> {
+    if octaves <= 1 {
+        Err("The number of octaves must be two or greater.")
+    } else if octaves > 30 {
+        Err("The number of octaves must be 30 or fewer.")
+    } else {
+        Ok(())
+    }
+}
+
+/// Replaces a non-finite sample (`NaN` or `inf`, which can arise from
+/// extreme input coordinates) with `0.0` so it doesn't corrupt the running
+/// sum of an octave loop.
+fn finite_or_zero(sample: f32) -> f32 {
+    if sample.is_finite() { sample } else { 0.0 }
+}
+
+/// Bundles the common fractal module knobs into a single value, following
+/// the widely-used NoiseParams convention for anisotropic terrain noise.
+/// Construct a module from one with [PinkNoise::from_params]
+/// (./struct.PinkNoise.html#method.from_params) or
+/// [BillowNoise::from_params](./struct.BillowNoise.html#method.from_params).
+#[deriving(Clone)]
+pub struct NoiseParams {
+    /// The "seed" used to ensure reproducibility and variation in the output
+    /// of the module.
+    pub seed: uint,
+
+    /// A per-axis divisor applied to the input coordinates before sampling,
+    /// generalizing the single scalar `frequency` so the X and Y feature
+    /// sizes can differ.
+    pub spread: Vector2<f32>,
+
+    /// The number of octaves is the number of successive samples of the
+    /// noise function the module will use to generate output.
+    pub octaves: uint,
+
+    /// The apparent "roughness" of the noise. This value controls the
+    /// amplitude falloff of the successive octaves.
+    pub persistence: f32,
+
+    /// The frequency multiplier between successive octaves.
+    pub lacunarity: f32,
+
+    /// A value the final result is multiplied by.
+    pub scale: f32,
+
+    /// A value added to the final result after `scale` is applied. Named
+    /// `bias` rather than `offset` (the latter being the more common name
+    /// for this knob) because `BillowNoise` already has a field called
+    /// `offset` with an unrelated meaning -- the artifact-reducing value
+    /// added before taking the absolute value of each octave's sample --
+    /// and `from_params` sets both on a `BillowNoise`.
+    pub bias: f32
+}
+
+impl Default for NoiseParams {
+    fn default() -> NoiseParams {
+        NoiseParams {
+            seed: 0, spread: Vector2 { x: 1.0, y: 1.0 }, octaves: 6,
+            persistence: 0.5, lacunarity: 2.0, scale: 1.0, bias: 0.0
+        }
+    }
+}
+
 /// PinkNoise is generated by calculating the contribution of a number of
 /// individual `octaves` of noise samples, and then adding them together.
 /// PinkNoise is a kind of fractal noise, because the contributions are self-
@@ -61,7 +163,21 @@ pub struct PinkNoise {
     /// The number of octaves is the number of successive additive samples of
     /// the noise function this module will use to generate output. It is
     /// essentially a measure of the level of "detail" in the output.
-    pub octaves: uint
+    pub octaves: uint,
+
+    /// A per-axis divisor applied to the input coordinates before
+    /// `frequency`, letting the X and Y feature sizes differ. Defaults to
+    /// `(1.0, 1.0)`, which has no effect. See [NoiseParams]
+    /// (./struct.NoiseParams.html).
+    pub spread: Vector2<f32>,
+
+    /// A value the final result is multiplied by. Defaults to `1.0`. See
+    /// [NoiseParams](./struct.NoiseParams.html).
+    pub scale: f32,
+
+    /// A value added to the final result after `scale` is applied. Defaults
+    /// to `0.0`. See [NoiseParams](./struct.NoiseParams.html).
+    pub bias: f32
 }
 
 impl PinkNoise {
@@ -70,45 +186,116 @@ impl PinkNoise {
     pub fn new(seed: uint) -> PinkNoise {
         PinkNoise { seed: seed, .. Default::default() }
     }
+
+    /// Create a new object configured from a [NoiseParams]
+    /// (./struct.NoiseParams.html) value.
+    pub fn from_params(params: NoiseParams) -> PinkNoise {
+        PinkNoise {
+            seed: params.seed, frequency: 1.0, persistence: params.persistence,
+            lacunarity: params.lacunarity, octaves: params.octaves,
+            spread: params.spread, scale: params.scale, bias: params.bias
+        }
+    }
 }
 
 impl Default for PinkNoise {
     fn default() -> PinkNoise {
         PinkNoise {
             seed: 0, frequency: 1.0, persistence: 0.5,
-            lacunarity: 2.0, octaves: 6
+            lacunarity: 2.0, octaves: 6,
+            spread: Vector2 { x: 1.0, y: 1.0 }, scale: 1.0, bias: 0.0
         }
     }
 }
 
 impl NoiseModule for PinkNoise {
     fn generate_2d(&self, v: Vector2<f32>) -> Result<f32, &str> {
-        if self.octaves <= 1 {
-            return Err("The number of octaves must be two or greater.");
-        } else if self.octaves > 30 {
-            return Err("The number of octaves must be less than 30.");
-        }
+        try!(check_octaves(self.octaves));
 
         let mut result: f32 = 0.0;
         let mut sample = Vector2 {
-            x: v.x * self.frequency, y: v.y * self.frequency
+            x: v.x * self.frequency / self.spread.x,
+            y: v.y * self.frequency / self.spread.y
         };
         let mut persistence = 1.0;
 
         for octave in range(0, self.octaves) {
-            result += persistence * snoise_2d(sample, self.seed + octave);
+            let signal = finite_or_zero(snoise_2d(sample, self.seed + octave));
+            result += persistence * signal;
             sample = Vector2 {
                 x: sample.x * self.lacunarity, y: sample.y * self.lacunarity
             };
             persistence *= self.persistence;
         }
 
-        Ok(result * PINKNOISE_SCALE)
+        let result = result * PINKNOISE_SCALE * self.scale + self.bias;
+        if !result.is_finite() {
+            return Err("Accumulated result was not finite.");
+        }
+
+        Ok(result)
+    }
+
+    fn generate_3d(&self, v: Vector3<f32>) -> Result<f32, &str> {
+        try!(check_octaves(self.octaves));
+
+        let mut result: f32 = 0.0;
+        let mut sample = Vector3 {
+            x: v.x * self.frequency / self.spread.x,
+            y: v.y * self.frequency / self.spread.y,
+            z: v.z * self.frequency
+        };
+        let mut persistence = 1.0;
+
+        for octave in range(0, self.octaves) {
+            let signal = finite_or_zero(snoise_3d(sample, self.seed + octave));
+            result += persistence * signal;
+            sample = Vector3 {
+                x: sample.x * self.lacunarity, y: sample.y * self.lacunarity,
+                z: sample.z * self.lacunarity
+            };
+            persistence *= self.persistence;
+        }
+
+        let result = result * PINKNOISE_SCALE * self.scale + self.bias;
+        if !result.is_finite() {
+            return Err("Accumulated result was not finite.");
+        }
+
+        Ok(result)
     }
 }
 
 impl Modifiable for PinkNoise {}
 
+impl Seedable for PinkNoise {
+    fn set_seed(self, seed: uint) -> PinkNoise {
+        PinkNoise { seed: seed, .. self }
+    }
+
+    fn seed(&self) -> uint {
+        self.seed
+    }
+}
+
+impl MultiFractal for PinkNoise {
+    fn set_octaves(self, octaves: uint) -> PinkNoise {
+        PinkNoise { octaves: octaves, .. self }
+    }
+
+    fn set_frequency(self, frequency: f32) -> PinkNoise {
+        PinkNoise { frequency: frequency, .. self }
+    }
+
+    fn set_lacunarity(self, lacunarity: f32) -> PinkNoise {
+        PinkNoise { lacunarity: lacunarity, .. self }
+    }
+
+    fn set_persistence(self, persistence: f32) -> PinkNoise {
+        PinkNoise { persistence: persistence, .. self }
+    }
+}
+
 /// BillowNoise is quite smilar to PinkNoise, but uses the absolute value of the
 /// noise function to create a more puffy, cloud-like appearance.
 #[deriving(Clone)]
@@ -136,7 +323,21 @@ pub struct BillowNoise {
 
     /// The offset from zero, used to reduce visual artifacts when using the
     /// absolute value function.
-    pub offset: f32
+    pub offset: f32,
+
+    /// A per-axis divisor applied to the input coordinates before
+    /// `frequency`, letting the X and Y feature sizes differ. Defaults to
+    /// `(1.0, 1.0)`, which has no effect. See [NoiseParams]
+    /// (./struct.NoiseParams.html).
+    pub spread: Vector2<f32>,
+
+    /// A value the final result is multiplied by. Defaults to `1.0`. See
+    /// [NoiseParams](./struct.NoiseParams.html).
+    pub scale: f32,
+
+    /// A value added to the final result after `scale` is applied. Defaults
+    /// to `0.0`. See [NoiseParams](./struct.NoiseParams.html).
+    pub bias: f32
 }
 
 impl BillowNoise {
@@ -145,42 +346,544 @@ impl BillowNoise {
     pub fn new(seed: uint) -> BillowNoise {
         BillowNoise { seed: seed, .. Default::default() }
     }
+
+    /// Create a new object configured from a [NoiseParams]
+    /// (./struct.NoiseParams.html) value.
+    pub fn from_params(params: NoiseParams) -> BillowNoise {
+        BillowNoise {
+            seed: params.seed, frequency: 1.0, persistence: params.persistence,
+            lacunarity: params.lacunarity, octaves: params.octaves,
+            offset: 0.2,
+            spread: params.spread, scale: params.scale, bias: params.bias
+        }
+    }
 }
 
 impl Default for BillowNoise {
     fn default() -> BillowNoise {
         BillowNoise {
             seed: 0, frequency: 1.0, persistence: 0.5,
-            lacunarity: 2.0, offset: 0.2, octaves: 6
+            lacunarity: 2.0, offset: 0.2, octaves: 6,
+            spread: Vector2 { x: 1.0, y: 1.0 }, scale: 1.0, bias: 0.0
         }
     }
 }
 
 impl NoiseModule for BillowNoise {
     fn generate_2d(&self, v: Vector2<f32>) -> Result<f32, &str> {
-        if self.octaves <= 1 {
-            return Err("The number of octaves must be two or greater.");
-        } else if self.octaves > 30 {
-            return Err("The number of octaves must be less than 30.");
-        }
+        try!(check_octaves(self.octaves));
 
         let mut result: f32 = 0.0;
         let mut sample = Vector2 {
-            x: v.x * self.frequency, y: v.y * self.frequency
+            x: v.x * self.frequency / self.spread.x,
+            y: v.y * self.frequency / self.spread.y
         };
         let mut persistence = 1.0;
 
         for octave in range(0, self.octaves) {
-            result += persistence *
-                (snoise_2d(sample, self.seed + octave) + self.offset).abs();
+            let signal = finite_or_zero(
+                (snoise_2d(sample, self.seed + octave) + self.offset).abs());
+            result += persistence * signal;
             sample = Vector2 {
                 x: sample.x * self.lacunarity, y: sample.y * self.lacunarity
             };
             persistence *= self.persistence;
         }
 
-        Ok(result * BILLOWNOISE_SCALE * 2.0 - 1.0)
+        let result =
+            (result * BILLOWNOISE_SCALE * 2.0 - 1.0) * self.scale + self.bias;
+        if !result.is_finite() {
+            return Err("Accumulated result was not finite.");
+        }
+
+        Ok(result)
+    }
+
+    fn generate_3d(&self, v: Vector3<f32>) -> Result<f32, &str> {
+        try!(check_octaves(self.octaves));
+
+        let mut result: f32 = 0.0;
+        let mut sample = Vector3 {
+            x: v.x * self.frequency / self.spread.x,
+            y: v.y * self.frequency / self.spread.y,
+            z: v.z * self.frequency
+        };
+        let mut persistence = 1.0;
+
+        for octave in range(0, self.octaves) {
+            let signal = finite_or_zero(
+                (snoise_3d(sample, self.seed + octave) + self.offset).abs());
+            result += persistence * signal;
+            sample = Vector3 {
+                x: sample.x * self.lacunarity, y: sample.y * self.lacunarity,
+                z: sample.z * self.lacunarity
+            };
+            persistence *= self.persistence;
+        }
+
+        let result =
+            (result * BILLOWNOISE_SCALE * 2.0 - 1.0) * self.scale + self.bias;
+        if !result.is_finite() {
+            return Err("Accumulated result was not finite.");
+        }
+
+        Ok(result)
     }
 }
 
 impl Modifiable for BillowNoise {}
+
+impl Seedable for BillowNoise {
+    fn set_seed(self, seed: uint) -> BillowNoise {
+        BillowNoise { seed: seed, .. self }
+    }
+
+    fn seed(&self) -> uint {
+        self.seed
+    }
+}
+
+impl MultiFractal for BillowNoise {
+    fn set_octaves(self, octaves: uint) -> BillowNoise {
+        BillowNoise { octaves: octaves, .. self }
+    }
+
+    fn set_frequency(self, frequency: f32) -> BillowNoise {
+        BillowNoise { frequency: frequency, .. self }
+    }
+
+    fn set_lacunarity(self, lacunarity: f32) -> BillowNoise {
+        BillowNoise { lacunarity: lacunarity, .. self }
+    }
+
+    fn set_persistence(self, persistence: f32) -> BillowNoise {
+        BillowNoise { persistence: persistence, .. self }
+    }
+}
+
+/// RidgedMultiNoise produces sharp, ridge-like features by folding each
+/// octave's sample around zero and squaring it, so that values near zero
+/// become prominent ridges rather than being smoothed away. This makes it
+/// well suited to mountain ranges and canyon-like terrain, in contrast to
+/// the rounded hills of [PinkNoise](./struct.PinkNoise.html) or the puffy
+/// look of [BillowNoise](./struct.BillowNoise.html).
+#[deriving(Clone)]
+pub struct RidgedMultiNoise {
+    /// The "seed" used to ensure reproducibility and variation in the output
+    /// of the module.
+    pub seed: uint,
+
+    /// The scale of the noise. Setting this value is equivalent to scaling
+    /// all input coordinates by the same value.
+    pub frequency: f32,
+
+    /// The frequency multiplier between successive octaves.
+    pub lacunarity: f32,
+
+    /// The number of octaves is the number of successive samples of the
+    /// noise function this module will use to generate output. It is
+    /// essentially a measure of the level of "detail" in the output.
+    pub octaves: uint,
+
+    /// The value subtracted from the absolute value of each octave's sample
+    /// before squaring. Values near `1.0` produce the sharpest ridges.
+    pub offset: f32,
+
+    /// Controls how quickly the weight of successive octaves is scaled down
+    /// by the strength of the previous octave. Larger values keep later
+    /// octaves closer to full weight, adding more fine detail.
+    pub gain: f32,
+
+    /// The fractal increment exponent, controlling how quickly the
+    /// amplitude of successive octaves falls off. Higher values produce
+    /// smoother, less detailed output.
+    pub h: f32
+}
+
+impl RidgedMultiNoise {
+    /// Create a new object with the seed `seed` and all parameters set to
+    /// their default values.
+    pub fn new(seed: uint) -> RidgedMultiNoise {
+        RidgedMultiNoise { seed: seed, .. Default::default() }
+    }
+}
+
+impl Default for RidgedMultiNoise {
+    fn default() -> RidgedMultiNoise {
+        RidgedMultiNoise {
+            seed: 0, frequency: 1.0, lacunarity: 2.0, octaves: 6,
+            offset: 1.0, gain: 2.0, h: 1.0
+        }
+    }
+}
+
+impl NoiseModule for RidgedMultiNoise {
+    fn generate_2d(&self, v: Vector2<f32>) -> Result<f32, &str> {
+        try!(check_octaves(self.octaves));
+
+        let mut result: f32 = 0.0;
+        let mut weight: f32 = 1.0;
+        let mut spectral_weight = 1.0f32;
+        let mut sample = Vector2 {
+            x: v.x * self.frequency, y: v.y * self.frequency
+        };
+
+        for octave in range(0, self.octaves) {
+            let mut signal = snoise_2d(sample, self.seed + octave);
+            signal = self.offset - signal.abs();
+            signal *= signal;
+            signal *= weight;
+
+            result += signal * spectral_weight;
+
+            weight = signal * self.gain;
+            weight = if weight > 1.0 {
+                1.0
+            } else if weight < 0.0 {
+                0.0
+            } else {
+                weight
+            };
+
+            sample = Vector2 {
+                x: sample.x * self.lacunarity, y: sample.y * self.lacunarity
+            };
+            spectral_weight *= self.lacunarity.powf(-self.h);
+        }
+
+        Ok(result)
+    }
+}
+
+impl Modifiable for RidgedMultiNoise {}
+
+impl Seedable for RidgedMultiNoise {
+    fn set_seed(self, seed: uint) -> RidgedMultiNoise {
+        RidgedMultiNoise { seed: seed, .. self }
+    }
+
+    fn seed(&self) -> uint {
+        self.seed
+    }
+}
+
+impl MultiFractal for RidgedMultiNoise {
+    fn set_octaves(self, octaves: uint) -> RidgedMultiNoise {
+        RidgedMultiNoise { octaves: octaves, .. self }
+    }
+
+    fn set_frequency(self, frequency: f32) -> RidgedMultiNoise {
+        RidgedMultiNoise { frequency: frequency, .. self }
+    }
+
+    fn set_lacunarity(self, lacunarity: f32) -> RidgedMultiNoise {
+        RidgedMultiNoise { lacunarity: lacunarity, .. self }
+    }
+}
+
+/// HeteroTerrainNoise models Musgrave's "heterogeneous terrain" function,
+/// where each octave's contribution is scaled by the accumulated value so
+/// far. This produces output that is comparatively flat in valleys, but
+/// grows rough and detailed on peaks, unlike the uniformly self-similar
+/// detail of [PinkNoise](./struct.PinkNoise.html).
+#[deriving(Clone)]
+pub struct HeteroTerrainNoise {
+    /// The "seed" used to ensure reproducibility and variation in the output
+    /// of the module.
+    pub seed: uint,
+
+    /// The scale of the noise. Setting this value is equivalent to scaling
+    /// all input coordinates by the same value.
+    pub frequency: f32,
+
+    /// The frequency multiplier between successive octaves.
+    pub lacunarity: f32,
+
+    /// The number of octaves is the number of successive samples of the
+    /// noise function this module will use to generate output. It is
+    /// essentially a measure of the level of "detail" in the output.
+    pub octaves: uint,
+
+    /// The fractal increment exponent, controlling how quickly the
+    /// per-octave power falls off. Higher values produce smoother, less
+    /// detailed output.
+    pub h: f32,
+
+    /// An offset added to each octave's sample before it modulates the
+    /// accumulated value, used to keep the terrain from flattening out
+    /// entirely in valleys.
+    pub offset: f32
+}
+
+impl HeteroTerrainNoise {
+    /// Create a new object with the seed `seed` and all parameters set to
+    /// their default values.
+    pub fn new(seed: uint) -> HeteroTerrainNoise {
+        HeteroTerrainNoise { seed: seed, .. Default::default() }
+    }
+}
+
+impl Default for HeteroTerrainNoise {
+    fn default() -> HeteroTerrainNoise {
+        HeteroTerrainNoise {
+            seed: 0, frequency: 1.0, lacunarity: 2.0, octaves: 6,
+            h: 0.25, offset: 1.0
+        }
+    }
+}
+
+impl NoiseModule for HeteroTerrainNoise {
+    fn generate_2d(&self, v: Vector2<f32>) -> Result<f32, &str> {
+        try!(check_octaves(self.octaves));
+
+        let mut sample = Vector2 {
+            x: v.x * self.frequency, y: v.y * self.frequency
+        };
+        let mut pwr = self.lacunarity.powf(-self.h);
+
+        let mut value = self.offset + snoise_2d(sample, self.seed);
+        sample = Vector2 {
+            x: sample.x * self.lacunarity, y: sample.y * self.lacunarity
+        };
+
+        for octave in range(1, self.octaves) {
+            let signal = (snoise_2d(sample, self.seed + octave) + self.offset)
+                * pwr * value;
+            value += signal;
+
+            sample = Vector2 {
+                x: sample.x * self.lacunarity, y: sample.y * self.lacunarity
+            };
+            pwr *= self.lacunarity.powf(-self.h);
+        }
+
+        Ok(value)
+    }
+}
+
+impl Modifiable for HeteroTerrainNoise {}
+
+impl Seedable for HeteroTerrainNoise {
+    fn set_seed(self, seed: uint) -> HeteroTerrainNoise {
+        HeteroTerrainNoise { seed: seed, .. self }
+    }
+
+    fn seed(&self) -> uint {
+        self.seed
+    }
+}
+
+impl MultiFractal for HeteroTerrainNoise {
+    fn set_octaves(self, octaves: uint) -> HeteroTerrainNoise {
+        HeteroTerrainNoise { octaves: octaves, .. self }
+    }
+
+    fn set_frequency(self, frequency: f32) -> HeteroTerrainNoise {
+        HeteroTerrainNoise { frequency: frequency, .. self }
+    }
+
+    fn set_lacunarity(self, lacunarity: f32) -> HeteroTerrainNoise {
+        HeteroTerrainNoise { lacunarity: lacunarity, .. self }
+    }
+}
+
+/// HybridMultiNoise models Musgrave's "hybrid multifractal" function, which
+/// behaves like [PinkNoise](./struct.PinkNoise.html) in the early octaves but
+/// lets each successive octave's weight be driven by the running result, so
+/// that rougher areas accumulate detail faster than smooth ones.
+#[deriving(Clone)]
+pub struct HybridMultiNoise {
+    /// The "seed" used to ensure reproducibility and variation in the output
+    /// of the module.
+    pub seed: uint,
+
+    /// The scale of the noise. Setting this value is equivalent to scaling
+    /// all input coordinates by the same value.
+    pub frequency: f32,
+
+    /// The frequency multiplier between successive octaves.
+    pub lacunarity: f32,
+
+    /// The number of octaves is the number of successive samples of the
+    /// noise function this module will use to generate output. It is
+    /// essentially a measure of the level of "detail" in the output.
+    pub octaves: uint,
+
+    /// The fractal increment exponent, controlling how quickly the
+    /// per-octave power falls off. Higher values produce smoother, less
+    /// detailed output.
+    pub h: f32,
+
+    /// An offset added to each octave's sample before it contributes to the
+    /// running result and weight.
+    pub offset: f32
+}
+
+impl HybridMultiNoise {
+    /// Create a new object with the seed `seed` and all parameters set to
+    /// their default values.
+    pub fn new(seed: uint) -> HybridMultiNoise {
+        HybridMultiNoise { seed: seed, .. Default::default() }
+    }
+}
+
+impl Default for HybridMultiNoise {
+    fn default() -> HybridMultiNoise {
+        HybridMultiNoise {
+            seed: 0, frequency: 1.0, lacunarity: 2.0, octaves: 6,
+            h: 0.25, offset: 0.7
+        }
+    }
+}
+
+impl NoiseModule for HybridMultiNoise {
+    fn generate_2d(&self, v: Vector2<f32>) -> Result<f32, &str> {
+        try!(check_octaves(self.octaves));
+
+        let mut sample = Vector2 {
+            x: v.x * self.frequency, y: v.y * self.frequency
+        };
+        let mut pwr = 1.0f32;
+
+        let mut result = (snoise_2d(sample, self.seed) + self.offset) * pwr;
+        let mut weight = result;
+        sample = Vector2 {
+            x: sample.x * self.lacunarity, y: sample.y * self.lacunarity
+        };
+        pwr *= self.lacunarity.powf(-self.h);
+
+        for octave in range(1, self.octaves) {
+            if weight > 1.0 {
+                weight = 1.0;
+            }
+
+            let signal = (snoise_2d(sample, self.seed + octave) + self.offset)
+                * pwr;
+            result += weight * signal;
+            weight *= signal;
+
+            sample = Vector2 {
+                x: sample.x * self.lacunarity, y: sample.y * self.lacunarity
+            };
+            pwr *= self.lacunarity.powf(-self.h);
+        }
+
+        Ok(result)
+    }
+}
+
+impl Modifiable for HybridMultiNoise {}
+
+impl Seedable for HybridMultiNoise {
+    fn set_seed(self, seed: uint) -> HybridMultiNoise {
+        HybridMultiNoise { seed: seed, .. self }
+    }
+
+    fn seed(&self) -> uint {
+        self.seed
+    }
+}
+
+impl MultiFractal for HybridMultiNoise {
+    fn set_octaves(self, octaves: uint) -> HybridMultiNoise {
+        HybridMultiNoise { octaves: octaves, .. self }
+    }
+
+    fn set_frequency(self, frequency: f32) -> HybridMultiNoise {
+        HybridMultiNoise { frequency: frequency, .. self }
+    }
+
+    fn set_lacunarity(self, lacunarity: f32) -> HybridMultiNoise {
+        HybridMultiNoise { lacunarity: lacunarity, .. self }
+    }
+}
+
+/// Hashes a 2D lattice coordinate to a value in `[-1, 1]` using
+/// `squirrel_hash`, folding `y` into `x` with a large prime so the two axes
+/// don't alias each other.
+fn lattice_value(x: int, y: int, seed: u32) -> f32 {
+    let n = (x as i32).wrapping_add(198491317i32.wrapping_mul(y as i32));
+    let h = squirrel_hash(n, seed);
+    (h as f32 / 4294967295.0) * 2.0 - 1.0
+}
+
+/// The smoothstep function `3t^2 - 2t^3`, used to ease the interpolation
+/// between lattice corners in [ValueNoise](./struct.ValueNoise.html) so the
+/// output has a continuous derivative at lattice boundaries.
+fn smoothstep(t: f32) -> f32 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Linear interpolation between `a` and `b` by `t`.
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + t * (b - a)
+}
+
+/// ValueNoise is a lattice noise module built on `squirrel_hash` rather than
+/// a permutation table. It trades the smoothness of
+/// [PinkNoise](./struct.PinkNoise.html)'s simplex samples for being entirely
+/// state-free: the same coordinate always hashes to the same value, making
+/// it a good fit for chunked or streamed world generation where tiles must
+/// agree regardless of generation order.
+#[deriving(Clone)]
+pub struct ValueNoise {
+    /// The "seed" used to ensure reproducibility and variation in the output
+    /// of the module.
+    pub seed: uint,
+
+    /// The scale of the noise. Setting this value is equivalent to scaling
+    /// all input coordinates by the same value.
+    pub frequency: f32
+}
+
+impl ValueNoise {
+    /// Create a new object with the seed `seed` and all parameters set to
+    /// their default values.
+    pub fn new(seed: uint) -> ValueNoise {
+        ValueNoise { seed: seed, .. Default::default() }
+    }
+}
+
+impl Default for ValueNoise {
+    fn default() -> ValueNoise {
+        ValueNoise { seed: 0, frequency: 1.0 }
+    }
+}
+
+impl NoiseModule for ValueNoise {
+    fn generate_2d(&self, v: Vector2<f32>) -> Result<f32, &str> {
+        let x = v.x * self.frequency;
+        let y = v.y * self.frequency;
+
+        let x0 = x.floor();
+        let y0 = y.floor();
+        let x1 = x0 + 1.0;
+        let y1 = y0 + 1.0;
+
+        let sx = smoothstep(x - x0);
+        let sy = smoothstep(y - y0);
+
+        let seed = self.seed as u32;
+        let v00 = lattice_value(x0 as int, y0 as int, seed);
+        let v10 = lattice_value(x1 as int, y0 as int, seed);
+        let v01 = lattice_value(x0 as int, y1 as int, seed);
+        let v11 = lattice_value(x1 as int, y1 as int, seed);
+
+        let ix0 = lerp(v00, v10, sx);
+        let ix1 = lerp(v01, v11, sx);
+
+        Ok(lerp(ix0, ix1, sy))
+    }
+}
+
+impl Modifiable for ValueNoise {}
+
+impl Seedable for ValueNoise {
+    fn set_seed(self, seed: uint) -> ValueNoise {
+        ValueNoise { seed: seed, .. self }
+    }
+
+    fn seed(&self) -> uint {
+        self.seed
+    }
+}
@@ -0,0 +1,20 @@
+/*
+    This file is part of grunge, a coherent noise generation library.
+*/
+
+//! `grunge` is a coherent noise generation library, implementing the kind of
+//! gradient and fractal noise functions commonly used to generate textures,
+//! terrain, and other procedural content.
+//!
+//! See the [`fractal`](./fractal/index.html) module for the noise generators
+//! themselves, and [`primitives`](./primitives/index.html) for the
+//! lower-level building blocks they are implemented in terms of.
+
+#![feature(globs)]
+#![deny(missing_docs)]
+
+extern crate cgmath;
+
+pub mod primitives;
+pub mod modifiers;
+pub mod fractal;
@@ -0,0 +1,74 @@
+/*
+    This file is part of grunge, a coherent noise generation library.
+*/
+
+//! Combinators for post-processing the output of a [`NoiseModule`]
+//! (primitives::NoiseModule).
+//!
+//! `Modifiable` is implemented by every module in [`fractal`]
+//! (../fractal/index.html), so that their output can be chained through
+//! simple transforms like `scalebias` and `clamp` without introducing a new
+//! wrapper type for each combination.
+
+use cgmath::vector::{Vector2, Vector3};
+
+use primitives::NoiseModule;
+
+/// Extends a `NoiseModule` implementor with chainable output transforms.
+pub trait Modifiable: Sized {
+    /// Scale the output of this module by `scale` and then add `bias`.
+    fn scalebias(self, scale: f32, bias: f32) -> ScaleBias<Self> {
+        ScaleBias { source: self, scale: scale, bias: bias }
+    }
+
+    /// Clamp the output of this module to the range `[lower, upper]`.
+    fn clamp(self, lower: f32, upper: f32) -> Clamp<Self> {
+        Clamp { source: self, lower: lower, upper: upper }
+    }
+}
+
+/// Scales and offsets the output of a wrapped module. Created by
+/// [`Modifiable::scalebias`].
+pub struct ScaleBias<T> {
+    source: T,
+    scale: f32,
+    bias: f32,
+}
+
+impl<T: NoiseModule> NoiseModule for ScaleBias<T> {
+    fn generate_2d(&self, v: Vector2<f32>) -> Result<f32, &str> {
+        let value = try!(self.source.generate_2d(v));
+        Ok(value * self.scale + self.bias)
+    }
+
+    fn generate_3d(&self, v: Vector3<f32>) -> Result<f32, &str> {
+        let value = try!(self.source.generate_3d(v));
+        Ok(value * self.scale + self.bias)
+    }
+}
+
+impl<T> Modifiable for ScaleBias<T> {}
+
+/// Clamps the output of a wrapped module to a fixed range. Created by
+/// [`Modifiable::clamp`].
+pub struct Clamp<T> {
+    source: T,
+    lower: f32,
+    upper: f32,
+}
+
+impl<T: NoiseModule> NoiseModule for Clamp<T> {
+    fn generate_2d(&self, v: Vector2<f32>) -> Result<f32, &str> {
+        let value = try!(self.source.generate_2d(v));
+        Ok(if value < self.lower { self.lower }
+           else if value > self.upper { self.upper } else { value })
+    }
+
+    fn generate_3d(&self, v: Vector3<f32>) -> Result<f32, &str> {
+        let value = try!(self.source.generate_3d(v));
+        Ok(if value < self.lower { self.lower }
+           else if value > self.upper { self.upper } else { value })
+    }
+}
+
+impl<T> Modifiable for Clamp<T> {}
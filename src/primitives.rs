@@ -0,0 +1,210 @@
+/*
+    This file is part of grunge, a coherent noise generation library.
+*/
+
+//! Low-level coherent noise primitives.
+//!
+//! The types and functions here are the foundation that the fractal modules
+//! in [`fractal`](../fractal/index.html) are built on top of. Most users
+//! will not need to call `snoise_2d` directly, but it is exposed for anyone
+//! who wants to build their own noise module from scratch.
+
+use cgmath::vector::{Vector2, Vector3};
+
+/// A permutation of `0u8..255u8`, used to index into the gradient table
+/// below without needing to allocate or hash at call time.
+static PERM: [uint, ..256] = [
+    234, 9, 103, 60, 5, 79, 232, 229, 45, 51, 131, 3,
+    168, 29, 170, 216, 99, 161, 111, 204, 220, 209, 78, 89,
+    72, 191, 157, 119, 226, 184, 244, 134, 21, 61, 175, 15,
+    223, 100, 230, 28, 128, 185, 84, 208, 164, 44, 113, 105,
+    27, 85, 203, 146, 153, 130, 66, 42, 250, 140, 174, 133,
+    115, 4, 52, 73, 65, 10, 104, 238, 30, 211, 46, 121,
+    2, 190, 159, 172, 112, 156, 95, 47, 124, 177, 77, 202,
+    81, 38, 123, 13, 182, 242, 64, 33, 225, 0, 241, 122,
+    210, 37, 106, 163, 82, 98, 34, 218, 187, 214, 125, 132,
+    120, 219, 252, 32, 135, 215, 245, 48, 198, 222, 76, 231,
+    213, 192, 227, 144, 19, 152, 110, 12, 217, 126, 196, 201,
+    248, 148, 109, 138, 63, 249, 200, 36, 197, 101, 127, 145,
+    149, 54, 16, 167, 102, 80, 239, 181, 14, 83, 224, 142,
+    69, 176, 118, 171, 251, 136, 43, 246, 155, 18, 165, 68,
+    53, 90, 94, 41, 93, 162, 116, 212, 205, 25, 235, 193,
+    74, 58, 169, 199, 17, 180, 49, 147, 92, 158, 160, 75,
+    141, 20, 96, 31, 137, 117, 186, 11, 67, 233, 88, 91,
+    24, 97, 237, 247, 86, 195, 236, 39, 221, 87, 240, 178,
+    40, 206, 194, 1, 207, 71, 150, 114, 56, 107, 243, 179,
+    166, 183, 50, 143, 254, 154, 129, 59, 55, 23, 7, 8,
+    108, 151, 22, 139, 228, 253, 173, 26, 188, 35, 255, 62,
+    70, 189, 6, 57,
+];
+
+/// The eight gradient directions used by the 2D simplex noise below.
+static GRAD2: [[f32, ..2], ..8] = [
+    [1.0, 1.0], [-1.0, 1.0], [1.0, -1.0], [-1.0, -1.0],
+    [1.0, 0.0], [-1.0, 0.0], [0.0, 1.0], [0.0, -1.0],
+];
+
+/// The twelve edge-midpoint gradient directions used by the 3D simplex
+/// noise below, padded to sixteen entries so a 4-bit mask can index them.
+static GRAD3: [[f32, ..3], ..16] = [
+    [1.0, 1.0, 0.0], [-1.0, 1.0, 0.0], [1.0, -1.0, 0.0], [-1.0, -1.0, 0.0],
+    [1.0, 0.0, 1.0], [-1.0, 0.0, 1.0], [1.0, 0.0, -1.0], [-1.0, 0.0, -1.0],
+    [0.0, 1.0, 1.0], [0.0, -1.0, 1.0], [0.0, 1.0, -1.0], [0.0, -1.0, -1.0],
+    [1.0, 1.0, 0.0], [0.0, -1.0, 1.0], [-1.0, 1.0, 0.0], [0.0, -1.0, -1.0],
+];
+
+/// A type that can generate coherent noise values from input coordinates.
+///
+/// Fractal modules are built by sampling a `NoiseModule` once per octave at
+/// successively higher frequencies and combining the results.
+pub trait NoiseModule {
+    /// Generate a value at the two-dimensional coordinate `v`.
+    fn generate_2d(&self, v: Vector2<f32>) -> Result<f32, &str>;
+
+    /// Generate a value at the three-dimensional coordinate `v`. The default
+    /// implementation returns an error; modules that support 3D sampling
+    /// (such as volumetric clouds or caves, or animating a 2D field along a
+    /// time axis) override this method.
+    fn generate_3d(&self, v: Vector3<f32>) -> Result<f32, &str> {
+        Err("This module does not support 3D sampling.")
+    }
+}
+
+/// Look up a permutation table entry for lattice coordinate `i`, folded by
+/// `seed` so that the same coordinate produces different output for
+/// different seeds.
+fn perm(i: int, seed: uint) -> uint {
+    PERM[((i as uint) + seed) & 0xff]
+}
+
+/// 2D simplex noise, after Ken Perlin's improved algorithm as popularized by
+/// Stefan Gustavson. Returns a value in approximately the range `[-1, 1]`.
+pub fn snoise_2d(v: Vector2<f32>, seed: uint) -> f32 {
+    static F2: f32 = 0.366025403; // 0.5 * (sqrt(3.0) - 1.0)
+    static G2: f32 = 0.211324865; // (3.0 - sqrt(3.0)) / 6.0
+
+    let s = (v.x + v.y) * F2;
+    let i = (v.x + s).floor();
+    let j = (v.y + s).floor();
+
+    let t = (i + j) * G2;
+    let x0_origin = i - t;
+    let y0_origin = j - t;
+    let x0 = v.x - x0_origin;
+    let y0 = v.y - y0_origin;
+
+    let (i1, j1) = if x0 > y0 { (1i, 0i) } else { (0i, 1i) };
+
+    let x1 = x0 - i1 as f32 + G2;
+    let y1 = y0 - j1 as f32 + G2;
+    let x2 = x0 - 1.0 + 2.0 * G2;
+    let y2 = y0 - 1.0 + 2.0 * G2;
+
+    let ii = i as int;
+    let jj = j as int;
+
+    let gi0 = perm(ii + perm(jj, seed) as int, seed) & 7;
+    let gi1 = perm(ii + i1 + perm(jj + j1, seed) as int, seed) & 7;
+    let gi2 = perm(ii + 1 + perm(jj + 1, seed) as int, seed) & 7;
+
+    let corner = |x: f32, y: f32, gi: uint| -> f32 {
+        let t = 0.5 - x * x - y * y;
+        if t < 0.0 {
+            0.0
+        } else {
+            let t = t * t;
+            t * t * (GRAD2[gi][0] * x + GRAD2[gi][1] * y)
+        }
+    };
+
+    70.0 * (corner(x0, y0, gi0) + corner(x1, y1, gi1) + corner(x2, y2, gi2))
+}
+
+/// 3D simplex noise, after Ken Perlin's improved algorithm as popularized by
+/// Stefan Gustavson. Returns a value in approximately the range `[-1, 1]`.
+pub fn snoise_3d(v: Vector3<f32>, seed: uint) -> f32 {
+    static F3: f32 = 1.0 / 3.0;
+    static G3: f32 = 1.0 / 6.0;
+
+    let s = (v.x + v.y + v.z) * F3;
+    let i = (v.x + s).floor();
+    let j = (v.y + s).floor();
+    let k = (v.z + s).floor();
+
+    let t = (i + j + k) * G3;
+    let x0 = v.x - (i - t);
+    let y0 = v.y - (j - t);
+    let z0 = v.z - (k - t);
+
+    let (i1, j1, k1, i2, j2, k2) = if x0 >= y0 {
+        if y0 >= z0 {
+            (1i, 0i, 0i, 1i, 1i, 0i)
+        } else if x0 >= z0 {
+            (1i, 0i, 0i, 1i, 0i, 1i)
+        } else {
+            (0i, 0i, 1i, 1i, 0i, 1i)
+        }
+    } else {
+        if y0 < z0 {
+            (0i, 0i, 1i, 0i, 1i, 1i)
+        } else if x0 < z0 {
+            (0i, 1i, 0i, 0i, 1i, 1i)
+        } else {
+            (0i, 1i, 0i, 1i, 1i, 0i)
+        }
+    };
+
+    let x1 = x0 - i1 as f32 + G3;
+    let y1 = y0 - j1 as f32 + G3;
+    let z1 = z0 - k1 as f32 + G3;
+    let x2 = x0 - i2 as f32 + 2.0 * G3;
+    let y2 = y0 - j2 as f32 + 2.0 * G3;
+    let z2 = z0 - k2 as f32 + 2.0 * G3;
+    let x3 = x0 - 1.0 + 3.0 * G3;
+    let y3 = y0 - 1.0 + 3.0 * G3;
+    let z3 = z0 - 1.0 + 3.0 * G3;
+
+    let ii = i as int;
+    let jj = j as int;
+    let kk = k as int;
+
+    let hash = |di: int, dj: int, dk: int| -> uint {
+        perm(ii + di + perm(jj + dj + perm(kk + dk, seed) as int, seed) as int,
+             seed) & 15
+    };
+
+    let gi0 = hash(0, 0, 0);
+    let gi1 = hash(i1, j1, k1);
+    let gi2 = hash(i2, j2, k2);
+    let gi3 = hash(1, 1, 1);
+
+    let corner = |x: f32, y: f32, z: f32, gi: uint| -> f32 {
+        let t = 0.6 - x * x - y * y - z * z;
+        if t < 0.0 {
+            0.0
+        } else {
+            let t = t * t;
+            t * t * (GRAD3[gi][0] * x + GRAD3[gi][1] * y + GRAD3[gi][2] * z)
+        }
+    };
+
+    32.0 * (corner(x0, y0, z0, gi0) + corner(x1, y1, z1, gi1) +
+            corner(x2, y2, z2, gi2) + corner(x3, y3, z3, gi3))
+}
+
+/// A fast, table-free integer hash, after Squirrel Eiserloh's "SquirrelNoise"
+/// bit-noise functions. Unlike `snoise_2d`/`snoise_3d`, this needs no
+/// precomputed permutation table, so lattice points can be hashed in any
+/// order with no shared state -- useful for chunked or streamed world
+/// generation where the same coordinate must hash to the same value
+/// regardless of generation order.
+pub fn squirrel_hash(n: i32, seed: u32) -> u32 {
+    let mut m = (n as u32).wrapping_mul(0x68E31DA4);
+    m = m.wrapping_add(seed);
+    m ^= m >> 8;
+    m = m.wrapping_add(0xB5297A4D);
+    m ^= m << 8;
+    m = m.wrapping_mul(0x1B56C4E9);
+    m ^= m >> 8;
+    m
+}
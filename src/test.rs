@@ -8,8 +8,10 @@ extern crate test;
 extern crate cgmath;
 extern crate grunge;
 
-use grunge::primitives::snoise_2d;
-use grunge::modules::*;
+use cgmath::vector::{Vector2, Vector3};
+
+use grunge::primitives::{snoise_2d, squirrel_hash, NoiseModule};
+use grunge::fractal::*;
 
 #[bench]
 fn bench_simplex_noise_2d(b: &mut test::Bencher) {
@@ -41,3 +43,102 @@ fn test_geom_output() {
 fn test_boxes() {
     let noise = PinkNoise::new(0u).scalebias(0.5, 0.5).clamp(0.0, 1.0);
 }
+
+#[test]
+fn test_squirrel_hash_is_order_independent() {
+    // squirrel_hash keeps no state between calls, so sampling the same
+    // coordinates in a different order must still produce the same values,
+    // which is the whole point of using it for chunked/streamed world gen.
+    let a_first = squirrel_hash(5, 0);
+    let b_first = squirrel_hash(-3, 0);
+    let b_second = squirrel_hash(-3, 0);
+    let a_second = squirrel_hash(5, 0);
+
+    assert_eq!(a_first, a_second);
+    assert_eq!(b_first, b_second);
+}
+
+#[test]
+fn test_value_noise_is_deterministic() {
+    let noise = ValueNoise::new(7);
+    let p = Vector2 { x: 12.75, y: -33.5 };
+
+    assert_eq!(noise.generate_2d(p).unwrap(), noise.generate_2d(p).unwrap());
+}
+
+#[test]
+fn test_pink_noise_guards_non_finite_output() {
+    // A very large frequency drives later octaves' sample coordinates to
+    // `inf` after a few rounds of `lacunarity` scaling, which propagates
+    // `NaN` through the simplex math. The module must not panic, and must
+    // not leak a non-finite value back to the caller.
+    let pink = PinkNoise { frequency: 1.0e38, .. PinkNoise::new(0) };
+
+    match pink.generate_2d(Vector2 { x: 1.0, y: 1.0 }) {
+        Ok(value) => assert!(value.is_finite()),
+        Err(_) => {}
+    }
+}
+
+#[test]
+fn test_billow_noise_guards_non_finite_output() {
+    let billow = BillowNoise { frequency: 1.0e30, .. BillowNoise::new(0) };
+
+    match billow.generate_2d(Vector2 { x: 1.0, y: 1.0 }) {
+        Ok(value) => assert!(value.is_finite()),
+        Err(_) => {}
+    }
+}
+
+#[test]
+fn test_ridged_multi_is_deterministic() {
+    let ridged = RidgedMultiNoise::new(3);
+    let p = Vector2 { x: 4.5, y: -2.25 };
+
+    assert_eq!(ridged.generate_2d(p).unwrap(), ridged.generate_2d(p).unwrap());
+}
+
+#[test]
+fn test_hetero_terrain_is_deterministic() {
+    let hetero = HeteroTerrainNoise::new(11);
+    let p = Vector2 { x: 8.0, y: 19.5 };
+
+    assert_eq!(hetero.generate_2d(p).unwrap(), hetero.generate_2d(p).unwrap());
+}
+
+#[test]
+fn test_hybrid_multi_is_deterministic() {
+    let hybrid = HybridMultiNoise::new(11);
+    let p = Vector2 { x: 8.0, y: 19.5 };
+
+    assert_eq!(hybrid.generate_2d(p).unwrap(), hybrid.generate_2d(p).unwrap());
+}
+
+#[test]
+fn test_pink_billow_generate_3d_is_deterministic() {
+    let pink = PinkNoise::new(2);
+    let billow = BillowNoise::new(2);
+    let p = Vector3 { x: 1.5, y: -4.0, z: 2.25 };
+
+    assert_eq!(pink.generate_3d(p).unwrap(), pink.generate_3d(p).unwrap());
+    assert_eq!(billow.generate_3d(p).unwrap(), billow.generate_3d(p).unwrap());
+}
+
+#[test]
+fn test_multifractal_builder_chain() {
+    let pink = PinkNoise::new(0)
+        .set_seed(9)
+        .set_octaves(4)
+        .set_frequency(2.0)
+        .set_persistence(0.25)
+        .set_lacunarity(1.5);
+
+    assert_eq!(pink.seed(), 9);
+    assert_eq!(pink.octaves, 4);
+    assert_eq!(pink.frequency, 2.0);
+    assert_eq!(pink.persistence, 0.25);
+    assert_eq!(pink.lacunarity, 1.5);
+
+    let p = Vector2 { x: 3.0, y: 6.0 };
+    assert_eq!(pink.generate_2d(p).unwrap(), pink.generate_2d(p).unwrap());
+}